@@ -8,6 +8,7 @@ use zed_extension_api::{
 const DEFAULT_PORT: u16 = 3025;
 const DEFAULT_HOST: &str = "127.0.0.1";
 const DEFAULT_BROWSERTOOLS_NPX_COMMAND: &str = "@agentdeskai/browser-tools-server@1.2.0";
+const WEBDRIVER_ELEMENT_KEY: &str = "element-6066-11e4-a52e-4f735466cecf";
 
 #[derive(Debug, Deserialize)]
 struct BrowserToolsSettings {
@@ -40,6 +41,50 @@ struct ApiResponse {
     message: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct ConsoleLogEntry {
+    level: String,
+    message: String,
+    #[serde(default)]
+    timestamp: Option<i64>,
+    #[serde(default)]
+    source: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SelectedElement {
+    #[serde(rename = "tagName")]
+    tag_name: String,
+    #[serde(rename = "className")]
+    class_name: String,
+    id: String,
+    #[serde(rename = "innerText")]
+    inner_text: String,
+    #[serde(rename = "outerHTML", default)]
+    outer_html: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuditResult {
+    score: Option<f64>,
+    issues: Vec<AuditIssue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuditIssue {
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    severity: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WindowRect {
+    width: u32,
+    height: u32,
+}
+
 struct BrowserToolsExtension {
     port: u16,
     host: String,
@@ -87,18 +132,28 @@ impl zed::Extension for BrowserToolsExtension {
     fn complete_slash_command_argument(
         &self,
         command: SlashCommand,
-        _args: Vec<String>,
+        args: Vec<String>,
     ) -> Result<Vec<SlashCommandArgumentCompletion>, String> {
         match command.name.as_str() {
-            "browser-capture" => Ok(vec![
-                create_completion("Screenshot", "screenshot"),
-                create_completion("Console Logs", "logs"),
-                create_completion("Console Errors", "errors"),
-                create_completion("Network Logs", "network"),
-                create_completion("Network Errors", "network-errors"),
-                create_completion("Clear Logs", "clear"),
-                create_completion("DOM Element", "element"),
-            ]),
+            "browser-capture" => match args.first().map(|s| s.as_str()) {
+                Some("find") | Some("find-all") => Ok(vec![
+                    create_completion("CSS Selector", "css selector"),
+                    create_completion("XPath", "xpath"),
+                    create_completion("Tag Name", "tag name"),
+                    create_completion("Link Text", "link text"),
+                ]),
+                _ => Ok(vec![
+                    create_completion("Screenshot", "screenshot"),
+                    create_completion("Console Logs", "logs"),
+                    create_completion("Console Errors", "errors"),
+                    create_completion("Network Logs", "network"),
+                    create_completion("Network Errors", "network-errors"),
+                    create_completion("Clear Logs", "clear"),
+                    create_completion("DOM Element", "element"),
+                    create_completion("Find Element", "find"),
+                    create_completion("Find All Elements", "find-all"),
+                ]),
+            },
             "browser-audit" => Ok(vec![
                 create_completion("Accessibility", "accessibility"),
                 create_completion("Performance", "performance"),
@@ -110,6 +165,32 @@ impl zed::Extension for BrowserToolsExtension {
             "browser-debug" => Ok(vec![
                 create_completion("Start Debugger Mode", "start"),
             ]),
+            "browser-navigate" => Ok(vec![
+                create_completion("Go To URL", "goto"),
+                create_completion("Back", "back"),
+                create_completion("Forward", "forward"),
+                create_completion("Refresh", "refresh"),
+            ]),
+            "browser-interact" => Ok(vec![
+                create_completion("Click", "click"),
+                create_completion("Type", "type"),
+                create_completion("Hover", "hover"),
+                create_completion("Key Press", "key"),
+            ]),
+            "browser-eval" => Ok(vec![]),
+            "browser-storage" => Ok(vec![
+                create_completion("Get Cookies", "get-cookies"),
+                create_completion("Add Cookie", "add-cookie"),
+                create_completion("Delete Cookie", "delete-cookie"),
+                create_completion("Delete All Cookies", "delete-all-cookies"),
+                create_completion("Local Storage", "local-storage"),
+            ]),
+            "browser-viewport" => Ok(vec![
+                create_completion("Set Size", "set"),
+                create_completion("Maximize", "maximize"),
+                create_completion("Minimize", "minimize"),
+                create_completion("Fullscreen", "fullscreen"),
+            ]),
             command => Err(format!("unknown slash command: \"{command}\"")),
         }
     }
@@ -127,7 +208,7 @@ impl zed::Extension for BrowserToolsExtension {
             return Err("No argument provided. Please select an option.".to_string());
         }
 
-        let (api_endpoint, method, api_params) = get_api_params(command_name, arg)?;
+        let (api_endpoint, method, api_params) = get_api_params(command_name, arg, &args)?;
         let api_url = format!("http://{}:{}/{}", self.host, self.port, api_endpoint);
         let result_text = process_api_request(&api_url, &method, api_params, command_name, arg)?;
         let section_label = get_section_label(command_name, arg);
@@ -157,7 +238,11 @@ fn get_current_timestamp() -> i64 {
         .as_millis() as i64
 }
 
-fn get_api_params(command_name: &str, arg: &str) -> Result<(String, String, serde_json::Value), String> {
+fn get_api_params(
+    command_name: &str,
+    arg: &str,
+    args: &[String],
+) -> Result<(String, String, serde_json::Value), String> {
     let timestamp = get_current_timestamp();
 
     match (command_name, arg) {
@@ -168,6 +253,8 @@ fn get_api_params(command_name: &str, arg: &str) -> Result<(String, String, serd
         ("browser-capture", "network-errors") => Ok(("network-errors".to_string(), "GET".to_string(), serde_json::json!({}))),
         ("browser-capture", "clear") => Ok(("wipelogs".to_string(), "POST".to_string(), serde_json::json!({}))),
         ("browser-capture", "element") => Ok(("selected-element".to_string(), "GET".to_string(), serde_json::json!({}))),
+        ("browser-capture", "find") => Ok(("find-element".to_string(), "POST".to_string(), build_locator(args)?)),
+        ("browser-capture", "find-all") => Ok(("find-elements".to_string(), "POST".to_string(), build_locator(args)?)),
 
         ("browser-audit", "accessibility") => Ok(("accessibility-audit".to_string(), "POST".to_string(), serde_json::json!({
             "category": "accessibility",
@@ -203,10 +290,231 @@ fn get_api_params(command_name: &str, arg: &str) -> Result<(String, String, serd
             "timestamp": timestamp
         }))),
 
+        ("browser-navigate", "goto") => {
+            let url = args.get(1).map(|s| s.as_str()).unwrap_or("");
+            if url.is_empty() {
+                return Err("browser-navigate goto requires a URL argument".to_string());
+            }
+            Ok(("navigate-goto".to_string(), "POST".to_string(), serde_json::json!({ "url": url })))
+        },
+        ("browser-navigate", "back") => Ok(("navigate-back".to_string(), "POST".to_string(), serde_json::json!({}))),
+        ("browser-navigate", "forward") => Ok(("navigate-forward".to_string(), "POST".to_string(), serde_json::json!({}))),
+        ("browser-navigate", "refresh") => Ok(("navigate-refresh".to_string(), "POST".to_string(), serde_json::json!({}))),
+
+        ("browser-interact", "click") => {
+            let selector = args.get(1..).map(|rest| rest.join(" ")).unwrap_or_default();
+            if selector.is_empty() {
+                return Err("browser-interact click requires a selector argument".to_string());
+            }
+            Ok(("actions".to_string(), "POST".to_string(), build_click(&selector)))
+        },
+        ("browser-interact", "type") => {
+            let text = args.get(1..).map(|rest| rest.join(" ")).unwrap_or_default();
+            if text.is_empty() {
+                return Err("browser-interact type requires a text argument".to_string());
+            }
+            Ok(("actions".to_string(), "POST".to_string(), build_type(&text)))
+        },
+        ("browser-interact", "hover") => {
+            let selector = args.get(1..).map(|rest| rest.join(" ")).unwrap_or_default();
+            if selector.is_empty() {
+                return Err("browser-interact hover requires a selector argument".to_string());
+            }
+            Ok(("actions".to_string(), "POST".to_string(), build_hover(&selector)))
+        },
+        ("browser-interact", "key") => {
+            let key = args.get(1).map(|s| s.as_str()).unwrap_or("");
+            if key.is_empty() {
+                return Err("browser-interact key requires a key argument".to_string());
+            }
+            Ok(("actions".to_string(), "POST".to_string(), build_key(key)))
+        },
+
+        ("browser-storage", "get-cookies") => Ok(("get-cookies".to_string(), "GET".to_string(), serde_json::json!({}))),
+        ("browser-storage", "add-cookie") => {
+            let spec = args.get(1).map(|s| s.as_str()).unwrap_or("");
+            if spec.is_empty() {
+                return Err("browser-storage add-cookie requires a \"name=value;domain=...;path=...;secure\" spec".to_string());
+            }
+            Ok(("add-cookie".to_string(), "POST".to_string(), serde_json::json!({ "cookie": parse_cookie_spec(spec)? })))
+        },
+        ("browser-storage", "delete-cookie") => {
+            let name = args.get(1).map(|s| s.as_str()).unwrap_or("");
+            if name.is_empty() {
+                return Err("browser-storage delete-cookie requires a cookie name".to_string());
+            }
+            Ok(("delete-cookie".to_string(), "POST".to_string(), serde_json::json!({ "name": name })))
+        },
+        ("browser-storage", "delete-all-cookies") => Ok(("delete-all-cookies".to_string(), "POST".to_string(), serde_json::json!({}))),
+        ("browser-storage", "local-storage") => Ok(("local-storage".to_string(), "GET".to_string(), serde_json::json!({}))),
+
+        ("browser-viewport", "set") => {
+            let size = args.get(1).map(|s| s.as_str()).unwrap_or("");
+            Ok(("window-rect".to_string(), "POST".to_string(), parse_viewport_size(size)?))
+        },
+        ("browser-viewport", "maximize") => Ok(("window-maximize".to_string(), "POST".to_string(), serde_json::json!({}))),
+        ("browser-viewport", "minimize") => Ok(("window-minimize".to_string(), "POST".to_string(), serde_json::json!({}))),
+        ("browser-viewport", "fullscreen") => Ok(("window-fullscreen".to_string(), "POST".to_string(), serde_json::json!({}))),
+
+        ("browser-eval", _) => {
+            let script = args.join(" ");
+            if script.is_empty() {
+                return Err("browser-eval requires a script argument".to_string());
+            }
+            Ok(("execute-script".to_string(), "POST".to_string(), serde_json::json!({
+                "script": script,
+                "args": []
+            })))
+        },
+
         (command, arg) => Err(format!("Unknown command or argument: {command} {arg}")),
     }
 }
 
+fn parse_viewport_size(size: &str) -> Result<serde_json::Value, String> {
+    let (width, height) = size
+        .split_once('x')
+        .ok_or_else(|| format!("browser-viewport set requires a \"WIDTHxHEIGHT\" size, got: \"{size}\""))?;
+
+    let width: u32 = width
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid width in viewport size: \"{size}\""))?;
+    let height: u32 = height
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid height in viewport size: \"{size}\""))?;
+
+    Ok(serde_json::json!({
+        "width": width,
+        "height": height,
+        "x": serde_json::Value::Null,
+        "y": serde_json::Value::Null,
+    }))
+}
+
+fn parse_cookie_spec(spec: &str) -> Result<serde_json::Value, String> {
+    let mut parts = spec.split(';');
+
+    let (name, value) = parts
+        .next()
+        .and_then(|pair| pair.split_once('='))
+        .ok_or_else(|| format!("Invalid cookie spec, expected \"name=value\": {spec}"))?;
+
+    let mut domain: Option<String> = None;
+    let mut path: Option<String> = None;
+    let mut secure = false;
+
+    for attribute in parts {
+        let attribute = attribute.trim();
+        if attribute.eq_ignore_ascii_case("secure") {
+            secure = true;
+        } else if let Some((key, value)) = attribute.split_once('=') {
+            match key.trim().to_lowercase().as_str() {
+                "domain" => domain = Some(value.trim().to_string()),
+                "path" => path = Some(value.trim().to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(serde_json::json!({
+        "name": name.trim(),
+        "value": value.trim(),
+        "domain": domain,
+        "path": path.unwrap_or_else(|| "/".to_string()),
+        "secure": secure,
+        "httpOnly": false,
+        "expiry": serde_json::Value::Null,
+    }))
+}
+
+const LOCATOR_STRATEGIES: &[&[&str]] = &[&["css", "selector"], &["xpath"], &["tag", "name"], &["link", "text"]];
+
+fn build_locator(args: &[String]) -> Result<serde_json::Value, String> {
+    let rest = args.get(1..).unwrap_or(&[]);
+
+    let strategy_tokens = LOCATOR_STRATEGIES
+        .iter()
+        .find(|tokens| {
+            rest.len() >= tokens.len()
+                && rest[..tokens.len()].iter().map(|s| s.as_str()).eq(tokens.iter().copied())
+        })
+        .ok_or_else(|| {
+            "browser-capture find requires a locator strategy (css selector, xpath, tag name, link text) and a value, e.g. \"find css selector #id\""
+                .to_string()
+        })?;
+
+    let strategy = strategy_tokens.join(" ");
+    let value = rest[strategy_tokens.len()..].join(" ");
+
+    if value.is_empty() {
+        return Err(format!("browser-capture find requires a locator value after the \"{strategy}\" strategy"));
+    }
+
+    Ok(serde_json::json!({ "using": strategy, "value": value }))
+}
+
+fn pointer_move_to(selector: &str) -> serde_json::Value {
+    serde_json::json!({
+        "type": "pointerMove",
+        "x": 0,
+        "y": 0,
+        "origin": { "type": "element", "selector": selector }
+    })
+}
+
+fn build_click(selector: &str) -> serde_json::Value {
+    serde_json::json!([{
+        "id": "pointer1",
+        "type": "pointer",
+        "actions": [
+            pointer_move_to(selector),
+            { "type": "pointerDown", "button": 0 },
+            { "type": "pause", "duration": 50 },
+            { "type": "pointerUp", "button": 0 }
+        ]
+    }])
+}
+
+fn build_hover(selector: &str) -> serde_json::Value {
+    serde_json::json!([{
+        "id": "pointer1",
+        "type": "pointer",
+        "actions": [pointer_move_to(selector)]
+    }])
+}
+
+fn build_type(text: &str) -> serde_json::Value {
+    let actions = text
+        .chars()
+        .flat_map(|c| {
+            let value = c.to_string();
+            [
+                serde_json::json!({ "type": "keyDown", "value": value.clone() }),
+                serde_json::json!({ "type": "keyUp", "value": value }),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::json!([{
+        "id": "keyboard1",
+        "type": "key",
+        "actions": actions
+    }])
+}
+
+fn build_key(key: &str) -> serde_json::Value {
+    serde_json::json!([{
+        "id": "keyboard1",
+        "type": "key",
+        "actions": [
+            { "type": "keyDown", "value": key },
+            { "type": "keyUp", "value": key }
+        ]
+    }])
+}
+
 fn get_section_label<'a>(command_name: &'a str, arg: &'a str) -> &'a str {
     match (command_name, arg) {
         ("browser-capture", "screenshot") => "Browser Screenshot",
@@ -216,6 +524,8 @@ fn get_section_label<'a>(command_name: &'a str, arg: &'a str) -> &'a str {
         ("browser-capture", "network-errors") => "Browser Network Errors",
         ("browser-capture", "clear") => "Clear Logs",
         ("browser-capture", "element") => "DOM Element",
+        ("browser-capture", "find") => "Found Element",
+        ("browser-capture", "find-all") => "Found Elements",
         ("browser-audit", "accessibility") => "Accessibility Audit",
         ("browser-audit", "performance") => "Performance Audit",
         ("browser-audit", "seo") => "SEO Audit",
@@ -223,6 +533,24 @@ fn get_section_label<'a>(command_name: &'a str, arg: &'a str) -> &'a str {
         ("browser-audit", "nextjs") => "NextJS Audit",
         ("browser-audit", "all") => "All Audits",
         ("browser-debug", "start") => "Debugger Mode",
+        ("browser-navigate", "goto") => "Navigate To URL",
+        ("browser-navigate", "back") => "Navigate Back",
+        ("browser-navigate", "forward") => "Navigate Forward",
+        ("browser-navigate", "refresh") => "Refresh Page",
+        ("browser-interact", "click") => "Click Element",
+        ("browser-interact", "type") => "Type Text",
+        ("browser-interact", "hover") => "Hover Element",
+        ("browser-interact", "key") => "Key Press",
+        ("browser-eval", _) => "Executed Script",
+        ("browser-storage", "get-cookies") => "Cookies",
+        ("browser-storage", "add-cookie") => "Cookie Added",
+        ("browser-storage", "delete-cookie") => "Cookie Deleted",
+        ("browser-storage", "delete-all-cookies") => "Cookies Cleared",
+        ("browser-storage", "local-storage") => "Local Storage",
+        ("browser-viewport", "set") => "Viewport Resized",
+        ("browser-viewport", "maximize") => "Window Maximized",
+        ("browser-viewport", "minimize") => "Window Minimized",
+        ("browser-viewport", "fullscreen") => "Window Fullscreen",
         _ => "Browser Tools"
     }
 }
@@ -259,10 +587,22 @@ fn get_error_message(command_name: &str, arg: &str) -> String {
             "Failed to clear logs. Make sure BrowserTools extension is running in Chrome.".to_string(),
         ("browser-capture", "element") =>
             "Failed to get DOM element. Make sure BrowserTools extension is running in Chrome.".to_string(),
+        ("browser-capture", "find") | ("browser-capture", "find-all") =>
+            "Failed to find element. Make sure BrowserTools extension is running in Chrome.".to_string(),
         ("browser-audit", _) =>
             "Failed to run audit. Make sure BrowserTools extension is running in Chrome.".to_string(),
         ("browser-debug", _) =>
             "Failed to start debugger. Make sure BrowserTools extension is running in Chrome.".to_string(),
+        ("browser-navigate", _) =>
+            "Failed to navigate. Make sure BrowserTools extension is running in Chrome.".to_string(),
+        ("browser-interact", _) =>
+            "Failed to perform interaction. Make sure BrowserTools extension is running in Chrome.".to_string(),
+        ("browser-eval", _) =>
+            "Failed to execute script. Make sure BrowserTools extension is running in Chrome.".to_string(),
+        ("browser-storage", _) =>
+            "Failed to access storage. Make sure BrowserTools extension is running in Chrome.".to_string(),
+        ("browser-viewport", _) =>
+            "Failed to resize window. Make sure BrowserTools extension is running in Chrome.".to_string(),
         _ => "Unknown command".to_string()
     }
 }
@@ -341,6 +681,7 @@ fn format_browser_tools_response(endpoint: &str, data: serde_json::Value) -> Str
                 .unwrap_or_else(|| "Browser logs cleared successfully.".to_string())
         },
         "selected-element" => format_selected_element(&data),
+        "find-element" | "find-elements" => format_found_elements(&data),
         "accessibility-audit" | "performance-audit" | "seo-audit" | "best-practices-audit" | "nextjs-audit" => {
             format_audit_response(endpoint, &data)
         },
@@ -350,6 +691,42 @@ fn format_browser_tools_response(endpoint: &str, data: serde_json::Value) -> Str
         "debug-mode" => {
             format!("Debugger Mode Results:\n\n{}", serde_json::to_string_pretty(&data).unwrap_or_default())
         },
+        "navigate-goto" | "navigate-back" | "navigate-forward" | "navigate-refresh" => {
+            data.get("message")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "Navigation completed.".to_string())
+        },
+        "actions" => {
+            data.get("message")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "Interaction performed successfully.".to_string())
+        },
+        "execute-script" => format_eval_result(&data),
+        "get-cookies" => format_cookies(&data),
+        "add-cookie" => {
+            data.get("message")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "Cookie added successfully.".to_string())
+        },
+        "delete-cookie" => {
+            data.get("message")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "Cookie deleted successfully.".to_string())
+        },
+        "delete-all-cookies" => {
+            data.get("message")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "All cookies cleared successfully.".to_string())
+        },
+        "local-storage" => {
+            format!("Local Storage:\n\n{}", serde_json::to_string_pretty(&data).unwrap_or_default())
+        },
+        "window-rect" | "window-maximize" | "window-minimize" | "window-fullscreen" => format_window_rect(&data),
         _ => serde_json::to_string_pretty(&data).unwrap_or_default()
     }
 }
@@ -357,10 +734,9 @@ fn format_browser_tools_response(endpoint: &str, data: serde_json::Value) -> Str
 fn format_console_logs(data: &serde_json::Value) -> String {
     if let Some(logs) = data.as_array() {
         let formatted_logs = logs.iter()
-            .map(|log| {
-                let level = log.get("level").and_then(|v| v.as_str()).unwrap_or("info");
-                let message = log.get("message").and_then(|v| v.as_str()).unwrap_or("");
-                format!("[{}] {}", level.to_uppercase(), message)
+            .map(|log| match serde_json::from_value::<ConsoleLogEntry>(log.clone()) {
+                Ok(entry) => format!("[{}] {}", entry.level.to_uppercase(), entry.message),
+                Err(e) => format!("[PARSE ERROR: {}] {}", e, log),
             })
             .collect::<Vec<String>>()
             .join("\n");
@@ -375,34 +751,141 @@ fn format_console_logs(data: &serde_json::Value) -> String {
     }
 }
 
-fn format_selected_element(data: &serde_json::Value) -> String {
-    if let Some(element) = data.get("element") {
-        let tag_name = element.get("tagName").and_then(|v| v.as_str()).unwrap_or("unknown");
-        let class_name = element.get("className").and_then(|v| v.as_str()).unwrap_or("");
-        let id = element.get("id").and_then(|v| v.as_str()).unwrap_or("");
-        let text = element.get("innerText").and_then(|v| v.as_str()).unwrap_or("");
+fn format_cookies(data: &serde_json::Value) -> String {
+    let cookies = data
+        .as_array()
+        .or_else(|| data.get("cookies").and_then(|v| v.as_array()));
+
+    match cookies {
+        Some(cookies) if !cookies.is_empty() => {
+            let rows = cookies
+                .iter()
+                .map(|cookie| {
+                    let name = cookie.get("name").and_then(|v| v.as_str()).unwrap_or("");
+                    let value = cookie.get("value").and_then(|v| v.as_str()).unwrap_or("");
+                    let domain = cookie.get("domain").and_then(|v| v.as_str()).unwrap_or("");
+                    let expiry = cookie
+                        .get("expiry")
+                        .and_then(|v| v.as_i64())
+                        .map(|e| e.to_string())
+                        .unwrap_or_else(|| "session".to_string());
+
+                    format!("- {name}={value} (domain: {domain}, expires: {expiry})")
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            format!("Cookies:\n\n{}", rows)
+        },
+        _ => "No cookies found.".to_string(),
+    }
+}
 
-        let mut element_info = format!("Selected DOM Element:\n- Tag: {}", tag_name);
+fn format_window_rect(data: &serde_json::Value) -> String {
+    match serde_json::from_value::<WindowRect>(data.clone()) {
+        Ok(rect) => format!("Window resized to {}x{}", rect.width, rect.height),
+        Err(e) => data
+            .get("message")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| {
+                format!(
+                    "Window size updated (parse error: {}):\n{}",
+                    e,
+                    serde_json::to_string_pretty(data).unwrap_or_default()
+                )
+            }),
+    }
+}
 
-        if !id.is_empty() {
-            element_info.push_str(&format!("\n- ID: {}", id));
-        }
+fn format_selected_element(data: &serde_json::Value) -> String {
+    match data.get("element") {
+        Some(element) => format!("Selected DOM Element:\n{}", format_element(element)),
+        None => "No DOM element selected. Click on an element in the browser to select it.".to_string(),
+    }
+}
 
-        if !class_name.is_empty() {
-            element_info.push_str(&format!("\n- Classes: {}", class_name));
+fn format_found_elements(data: &serde_json::Value) -> String {
+    if let Some(elements) = data.get("elements").and_then(|v| v.as_array()) {
+        if elements.is_empty() {
+            return "No elements matched the given locator.".to_string();
         }
 
-        if !text.is_empty() {
-            element_info.push_str(&format!("\n- Text: {}", text));
-        }
+        elements
+            .iter()
+            .enumerate()
+            .map(|(i, element)| format!("Match {}:\n{}", i + 1, format_element(element)))
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    } else if let Some(element) = data.get("element") {
+        format_element(element)
+    } else {
+        "No elements matched the given locator.".to_string()
+    }
+}
 
-        if let Some(html) = element.get("outerHTML").and_then(|v| v.as_str()) {
-            element_info.push_str(&format!("\n\nHTML:\n{}", html));
+fn format_element(element: &serde_json::Value) -> String {
+    let parsed = match serde_json::from_value::<SelectedElement>(element.clone()) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return format!(
+                "Failed to parse element data ({}):\n{}",
+                e,
+                serde_json::to_string_pretty(element).unwrap_or_default()
+            );
         }
+    };
 
-        element_info
-    } else {
-        "No DOM element selected. Click on an element in the browser to select it.".to_string()
+    let mut element_info = format!("- Tag: {}", parsed.tag_name);
+
+    if !parsed.id.is_empty() {
+        element_info.push_str(&format!("\n- ID: {}", parsed.id));
+    }
+
+    if !parsed.class_name.is_empty() {
+        element_info.push_str(&format!("\n- Classes: {}", parsed.class_name));
+    }
+
+    if !parsed.inner_text.is_empty() {
+        element_info.push_str(&format!("\n- Text: {}", parsed.inner_text));
+    }
+
+    if let Some(html) = parsed.outer_html.filter(|html| !html.is_empty()) {
+        element_info.push_str(&format!("\n\nHTML:\n{}", html));
+    }
+
+    element_info
+}
+
+fn format_eval_result(data: &serde_json::Value) -> String {
+    match data {
+        serde_json::Value::Object(map) if map.contains_key(WEBDRIVER_ELEMENT_KEY) => {
+            match serde_json::from_value::<SelectedElement>(data.clone()) {
+                Ok(_) => format_selected_element(&serde_json::json!({ "element": data })),
+                Err(_) => {
+                    let element_id = map.get(WEBDRIVER_ELEMENT_KEY).and_then(|v| v.as_str()).unwrap_or("unknown");
+                    format!(
+                        "Script returned an unresolved element reference ({}). It was not enriched with tag/class/text data; use `browser-capture find` to inspect it.",
+                        element_id
+                    )
+                }
+            }
+        },
+        serde_json::Value::Array(items) => {
+            format!(
+                "Array Result ({} item{}):\n\n{}",
+                items.len(),
+                if items.len() == 1 { "" } else { "s" },
+                serde_json::to_string_pretty(data).unwrap_or_default()
+            )
+        },
+        serde_json::Value::Object(_) => {
+            format!("Object Result:\n\n{}", serde_json::to_string_pretty(data).unwrap_or_default())
+        },
+        serde_json::Value::Null => "Script returned no value (null/undefined).".to_string(),
+        serde_json::Value::Bool(_) | serde_json::Value::Number(_) | serde_json::Value::String(_) => {
+            format!("Result: {}", data)
+        },
     }
 }
 
@@ -416,45 +899,45 @@ fn format_audit_response(endpoint: &str, data: &serde_json::Value) -> String {
         _ => "Unknown"
     };
 
-    // Try to extract score
-    let score = data.get("score")
-        .and_then(|v| v.as_f64())
+    let audit = match serde_json::from_value::<AuditResult>(data.clone()) {
+        Ok(audit) => audit,
+        Err(e) => {
+            return format!(
+                "{} Audit Results (parse error: {}):\n\n{}",
+                audit_type,
+                e,
+                serde_json::to_string_pretty(data).unwrap_or_default()
+            );
+        }
+    };
+
+    let score = audit
+        .score
         .map(|score| {
             let score_percentage = (score * 100.0).round() as i32;
             format!("Overall Score: {}%\n", score_percentage)
         })
         .unwrap_or_default();
 
-    // Try to extract issues
-    let issues = if let Some(issues) = data.get("issues").and_then(|v| v.as_array()) {
-        if issues.is_empty() {
-            "\nNo issues found!".to_string()
-        } else {
-            let mut issues_text = "\nIssues Found:\n".to_string();
-
-            for (i, issue) in issues.iter().enumerate() {
-                let title = issue.get("title").and_then(|v| v.as_str()).unwrap_or("Unknown issue");
-                let description = issue.get("description").and_then(|v| v.as_str()).unwrap_or("");
+    let issues = if audit.issues.is_empty() {
+        "\nNo issues found!".to_string()
+    } else {
+        let mut issues_text = "\nIssues Found:\n".to_string();
 
-                issues_text.push_str(&format!("\n{}. {}\n", i + 1, title));
-                if !description.is_empty() {
-                    issues_text.push_str(&format!("   {}\n", description));
-                }
+        for (i, issue) in audit.issues.iter().enumerate() {
+            issues_text.push_str(&format!("\n{}. {}\n", i + 1, issue.title));
+            if !issue.description.is_empty() {
+                issues_text.push_str(&format!("   {}\n", issue.description));
+            }
+            if let Some(severity) = &issue.severity {
+                issues_text.push_str(&format!("   Severity: {}\n", severity));
             }
-
-            issues_text
         }
-    } else {
-        String::new()
+
+        issues_text
     };
 
-    // If we extracted structured data, format it nicely
-    if !score.is_empty() || !issues.is_empty() {
-        format!("{} Audit Results:\n\n{}{}", audit_type, score, issues)
-    } else {
-        // Fall back to raw JSON if we couldn't extract structured data
-        format!("{} Audit Results:\n\n{}", audit_type, serde_json::to_string_pretty(data).unwrap_or_default())
-    }
+    format!("{} Audit Results:\n\n{}{}", audit_type, score, issues)
 }
 
 zed::register_extension!(BrowserToolsExtension);